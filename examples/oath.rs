@@ -36,7 +36,7 @@ fn main() -> anyhow::Result<()> {
 
     let response = match response.data {
         BulkResponseData::Totp(response) => response,
-        BulkResponseData::Hotp => anyhow::bail!("HOTP is not supported"),
+        BulkResponseData::Hotp => yubikey.calculate_hotp(true, opts.name.as_bytes(), &mut buf)?,
         BulkResponseData::Touch => {
             eprintln!("Touch YubiKey ...");
             yubikey.calculate(true, opts.name.as_bytes(), challenge, &mut buf)?
@@ -0,0 +1,98 @@
+//! A typed APDU builder and status-word decoding.
+
+use crate::{Error, Payload};
+
+// SEND REMAINING INSTRUCTION
+pub(crate) const SEND_REMAINING: &[u8] = &[0x00, 0xa5, 0x00, 0x00];
+
+/// A command APDU under construction in `buf`.
+pub(crate) struct Apdu<'a> {
+    buf: &'a mut Vec<u8>,
+}
+
+impl<'a> Apdu<'a> {
+    pub(crate) fn new(buf: &'a mut Vec<u8>, cls: u8, ins: u8, p1: u8, p2: u8) -> Self {
+        buf.clear();
+        buf.extend_from_slice(&[cls, ins, p1, p2, 0x00]);
+        Apdu { buf }
+    }
+
+    /// Appends raw, untagged bytes to the body (e.g. SELECT's AID).
+    pub(crate) fn raw(self, data: &[u8]) -> Self {
+        self.buf.extend_from_slice(data);
+        self
+    }
+
+    pub(crate) fn push<D: Payload>(self, tag: u8, data: D) -> Self {
+        self.buf.push(tag);
+        self.buf.push(data.len());
+        data.push_into(self.buf);
+        self
+    }
+
+    pub(crate) fn push_if<D: Payload>(self, condition: bool, tag: u8, data: D) -> Self {
+        if condition {
+            self.push(tag, data)
+        } else {
+            self
+        }
+    }
+
+    /// Patches in `Lc`, switching to the three-byte extended-length form if the body is too
+    /// big for a single byte. This only covers the overall command body; an individual TLV
+    /// object is still capped at 255 bytes (see [`Payload`]).
+    pub(crate) fn finish(self) -> &'a mut Vec<u8> {
+        let body_len = self.buf.len() - 5;
+        match u8::try_from(body_len) {
+            Ok(len) => self.buf[4] = len,
+            Err(_) => {
+                let len = (body_len as u16).to_be_bytes();
+                self.buf.splice(5..5, len);
+            }
+        }
+        self.buf
+    }
+}
+
+/// The status word trailing a response APDU.
+pub(crate) enum StatusWord {
+    Ok,
+    MoreData,
+    NoSpace,
+    NoSuchObject,
+    AuthRequired,
+    WrongSyntax,
+    GenericError,
+    Unknown(u16),
+}
+
+impl From<u16> for StatusWord {
+    fn from(code: u16) -> Self {
+        match code {
+            0x9000 => StatusWord::Ok,
+            0x6100..=0x61ff => StatusWord::MoreData,
+            0x6a84 => StatusWord::NoSpace,
+            0x6984 => StatusWord::NoSuchObject,
+            0x6982 => StatusWord::AuthRequired,
+            0x6a80 => StatusWord::WrongSyntax,
+            0x6581 => StatusWord::GenericError,
+            code => StatusWord::Unknown(code),
+        }
+    }
+}
+
+impl StatusWord {
+    /// Whether more response data is available via `SEND REMAINING`.
+    pub(crate) fn has_more(self) -> Result<bool, Error> {
+        match self {
+            StatusWord::Ok => Ok(false),
+            StatusWord::MoreData => Ok(true),
+            StatusWord::NoSpace => Err(Error::NoSpace),
+            StatusWord::NoSuchObject => Err(Error::NoSuchObject),
+            StatusWord::AuthRequired => Err(Error::AuthRequired),
+            StatusWord::WrongSyntax => Err(Error::WrongSyntax),
+            StatusWord::GenericError => Err(Error::GenericError),
+            StatusWord::Unknown(code) => Err(Error::UnknownCode(code)),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::apdu::Apdu;
 use crate::{Error, Payload, YubiKey};
 use std::fmt::{Display, Formatter};
 use std::mem::size_of;
@@ -42,15 +43,27 @@ impl YubiKey {
         challenge: C,
         buf: &mut Vec<u8>,
     ) -> Result<Response, Error> {
-        buf.clear();
-        buf.extend_from_slice(&[0x00, 0xa2, 0x00, if truncate { 0x01 } else { 0x00 }]);
-        buf.push(0x00);
-        Self::push(buf, 0x71, name);
-        Self::push(buf, 0x74, challenge);
-        let mut response = self.transmit(buf)?;
+        let apdu = Apdu::new(buf, 0x00, 0xa2, 0x00, if truncate { 0x01 } else { 0x00 })
+            .push(0x71, name)
+            .push(0x74, challenge);
+        let mut response = self.transmit(apdu)?;
         let (_, response) = Self::pop(&mut response, &[if truncate { 0x76 } else { 0x75 }])?;
         let response = response.try_into()?;
         tracing::debug!(response = ?response);
         Ok(response)
     }
+
+    /// Calculates the next code for an HOTP credential, incrementing its counter on the device.
+    ///
+    /// Unlike TOTP, HOTP credentials ignore the challenge sent to [`calculate`](YubiKey::calculate)
+    /// and instead advance their own stored counter, so no challenge needs to be provided here.
+    #[tracing::instrument(skip(self, buf))]
+    pub fn calculate_hotp(
+        &self,
+        truncate: bool,
+        name: &[u8],
+        buf: &mut Vec<u8>,
+    ) -> Result<Response, Error> {
+        self.calculate(truncate, name, &[][..], buf)
+    }
 }
@@ -1,3 +1,4 @@
+use crate::apdu::Apdu;
 use crate::{Error, EscapeAscii, Payload, YubiKey};
 use std::fmt;
 use std::iter;
@@ -20,6 +21,9 @@ impl fmt::Debug for BulkResponse<'_> {
 #[derive(Debug, Clone)]
 pub enum BulkResponseData {
     Totp(crate::calculate::Response),
+    /// The device only reports that this is an HOTP credential; fetching the actual code
+    /// requires a separate [`calculate_hotp`](YubiKey::calculate_hotp) call, which also
+    /// advances its counter.
     Hotp,
     Touch,
 }
@@ -33,11 +37,9 @@ impl YubiKey {
         buf: &'a mut Vec<u8>,
     ) -> Result<impl Iterator<Item = Result<BulkResponse<'a>, Error>> + 'a, Error> {
         let span = tracing::Span::current();
-        buf.clear();
-        buf.extend_from_slice(&[0x00, 0xa4, 0x00, if truncate { 0x01 } else { 0x00 }]);
-        buf.push(0x00);
-        Self::push(buf, 0x74, challenge);
-        let mut response = self.transmit(buf)?;
+        let apdu = Apdu::new(buf, 0x00, 0xa4, 0x00, if truncate { 0x01 } else { 0x00 })
+            .push(0x74, challenge);
+        let mut response = self.transmit(apdu)?;
         Ok(iter::from_fn(move || {
             let _enter = span.enter();
             if response.is_empty() {
@@ -0,0 +1,12 @@
+use crate::apdu::Apdu;
+use crate::{Error, YubiKey};
+
+impl YubiKey {
+    /// Removes a single credential. A missing `name` results in [`Error::NoSuchObject`].
+    #[tracing::instrument(skip(self, buf))]
+    pub fn delete(&self, name: &[u8], buf: &mut Vec<u8>) -> Result<(), Error> {
+        let apdu = Apdu::new(buf, 0x00, 0x02, 0x00, 0x00).push(0x71, name);
+        self.transmit(apdu)?;
+        Ok(())
+    }
+}
@@ -24,4 +24,8 @@ pub enum Error {
     GenericError,
     #[error("Non utf8 key name")]
     Utf8(#[from] Utf8Error),
+    #[error("Authentication failed")]
+    AuthFailed,
+    #[error("Invalid otpauth:// uri: {0}")]
+    InvalidUri(String),
 }
@@ -1,14 +1,25 @@
 //! https://developers.yubico.com/OATH/YKOATH_Protocol.html
 
+mod apdu;
 pub mod calculate;
 pub mod calculate_all;
+mod delete;
 mod error;
+mod list;
+pub mod otpauth;
+mod put;
+mod reset;
 pub mod select;
+mod set_code;
+mod validate;
 
+use crate::apdu::{Apdu, StatusWord, SEND_REMAINING};
 pub use crate::calculate::Response;
 pub use crate::calculate_all::{BulkResponse, BulkResponseData};
 pub use error::Error;
+use hmac::Hmac;
 use pcsc::{Card, Context, Protocols, Scope, ShareMode, MAX_BUFFER_SIZE};
+use sha1::Sha1;
 use std::fmt::{self, Debug, Write};
 use std::mem::size_of;
 
@@ -47,11 +58,8 @@ impl YubiKey {
     }
 
     #[tracing::instrument(skip_all)]
-    fn transmit<'a>(&self, buf: &'a mut Vec<u8>) -> Result<&'a [u8], Error> {
-        if buf.len() >= 5 {
-            // Lc
-            buf[4] = (buf.len() - 5) as _;
-        }
+    fn transmit<'a>(&self, apdu: Apdu<'a>) -> Result<&'a [u8], Error> {
+        let buf = apdu.finish();
         tracing::trace!(command = ?buf);
         let mid = buf.len();
         loop {
@@ -61,8 +69,7 @@ impl YubiKey {
             let command = if mid == len {
                 &occupied[..mid]
             } else {
-                // SEND REMAINING INSTRUCTION
-                &[0x00, 0xa5, 0x00, 0x00]
+                SEND_REMAINING
             };
             tracing::trace!(pcsc_command = ?command);
             let response = self.0.transmit(command, vacant)?;
@@ -73,29 +80,14 @@ impl YubiKey {
                 buf.pop().ok_or(Error::InsufficientData)?,
                 buf.pop().ok_or(Error::InsufficientData)?,
             ]);
-            match code {
-                0x9000 => {
-                    let response = &buf[mid..];
-                    tracing::trace!(response = ?response);
-                    break Ok(response);
-                }
-                0x6100..=0x61ff => Ok(()),
-                0x6a84 => Err(Error::NoSpace),
-                0x6984 => Err(Error::NoSuchObject),
-                0x6982 => Err(Error::AuthRequired),
-                0x6a80 => Err(Error::WrongSyntax),
-                0x6581 => Err(Error::GenericError),
-                _ => Err(Error::UnknownCode(code)),
-            }?
+            if !StatusWord::from(code).has_more()? {
+                let response = &buf[mid..];
+                tracing::trace!(response = ?response);
+                break Ok(response);
+            }
         }
     }
 
-    fn push<Data: Payload>(buf: &mut Vec<u8>, tag: u8, data: Data) {
-        buf.push(tag);
-        buf.push(data.len());
-        data.push_into(buf);
-    }
-
     fn pop<'a>(buf: &mut &'a [u8], tags: &[u8]) -> Result<(u8, &'a [u8]), Error> {
         let tag = *buf.first().ok_or(Error::InsufficientData)?;
         if tags.contains(&tag) {
@@ -109,6 +101,16 @@ impl YubiKey {
     }
 }
 
+/// Derives a 16-byte OATH authentication key from a user-supplied password, for use with
+/// [`YubiKey::validate`] and [`YubiKey::set_code`].
+///
+/// The salt should be the device's `name` as returned by [`select`](YubiKey::select).
+pub fn derive_key(password: &str, salt: &[u8]) -> [u8; 16] {
+    let mut key = [0u8; 16];
+    pbkdf2::pbkdf2::<Hmac<Sha1>>(password.as_bytes(), salt, 1000, &mut key);
+    key
+}
+
 #[derive(Debug)]
 pub enum Algorithm {
     HmacSha1,
@@ -116,6 +118,48 @@ pub enum Algorithm {
     HmacSha512,
 }
 
+impl Algorithm {
+    pub(crate) fn code(&self) -> u8 {
+        match self {
+            Algorithm::HmacSha1 => 0x01,
+            Algorithm::HmacSha256 => 0x02,
+            Algorithm::HmacSha512 => 0x03,
+        }
+    }
+
+    pub(crate) fn from_code(code: u8) -> Result<Self, Error> {
+        match code {
+            0x01 => Ok(Algorithm::HmacSha1),
+            0x02 => Ok(Algorithm::HmacSha256),
+            0x03 => Ok(Algorithm::HmacSha512),
+            code => Err(Error::UnexpectedValue(code)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OathType {
+    Hotp,
+    Totp,
+}
+
+impl OathType {
+    pub(crate) fn code(&self) -> u8 {
+        match self {
+            OathType::Hotp => 0x10,
+            OathType::Totp => 0x20,
+        }
+    }
+
+    pub(crate) fn from_code(code: u8) -> Result<Self, Error> {
+        match code {
+            0x10 => Ok(OathType::Hotp),
+            0x20 => Ok(OathType::Totp),
+            code => Err(Error::UnexpectedValue(code)),
+        }
+    }
+}
+
 struct EscapeAscii<'a>(&'a [u8]);
 
 impl fmt::Debug for EscapeAscii<'_> {
@@ -128,6 +172,9 @@ impl fmt::Debug for EscapeAscii<'_> {
     }
 }
 
+/// A single TLV data object's value. The length is a single byte, per YKOATH's tag/length
+/// framing, so an individual object is capped at 255 bytes regardless of any extended-length
+/// `Lc` on the enclosing APDU.
 pub trait Payload: Debug {
     fn push_into(&self, buf: &mut Vec<u8>);
     fn len(&self) -> u8;
@@ -139,7 +186,7 @@ impl Payload for &'_ [u8] {
     }
 
     fn len(&self) -> u8 {
-        <[u8]>::len(self) as _
+        u8::try_from(<[u8]>::len(self)).expect("payload exceeds the 255-byte TLV length limit")
     }
 }
 
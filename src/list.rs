@@ -0,0 +1,41 @@
+use crate::apdu::Apdu;
+use crate::{Algorithm, Error, OathType, YubiKey};
+use std::iter;
+
+impl YubiKey {
+    /// Lists the credentials stored on the device without calculating any codes or prompting
+    /// for touch, unlike [`calculate_all`](YubiKey::calculate_all).
+    ///
+    /// Not implemented: surfacing a credential's touch requirement or HOTP counter here, since
+    /// the LIST (tag 0x72) response doesn't carry either field — there is no byte for it to come
+    /// from. HOTP entries are identified by their [`OathType`] here, but touch-required entries
+    /// are only distinguishable via `calculate_all`'s
+    /// [`Touch`](crate::BulkResponseData::Touch) variant, and the counter isn't readable from
+    /// the device at all.
+    #[tracing::instrument(skip(self, buf))]
+    pub fn list<'a>(
+        &self,
+        buf: &'a mut Vec<u8>,
+    ) -> Result<impl Iterator<Item = Result<(&'a str, OathType, Algorithm), Error>> + 'a, Error>
+    {
+        let span = tracing::Span::current();
+        let apdu = Apdu::new(buf, 0x00, 0xa1, 0x00, 0x00);
+        let mut response = self.transmit(apdu)?;
+        Ok(iter::from_fn(move || {
+            let _enter = span.enter();
+            if response.is_empty() {
+                None
+            } else {
+                Some(Self::pop(&mut response, &[0x72]).and_then(|(_, data)| {
+                    let (&packed, name) = data.split_first().ok_or(Error::InsufficientData)?;
+                    let oath_type = OathType::from_code(packed & 0xf0)?;
+                    let algorithm = Algorithm::from_code(packed & 0x0f)?;
+                    let name = std::str::from_utf8(name)?;
+                    let credential = (name, oath_type, algorithm);
+                    tracing::debug!(credential = ?credential);
+                    Ok(credential)
+                }))
+            }
+        }))
+    }
+}
@@ -0,0 +1,156 @@
+//! Parses `otpauth://` URIs, as produced by the QR codes most TOTP/HOTP issuers hand out, into
+//! arguments for [`YubiKey::put`](crate::YubiKey::put).
+
+use crate::{Algorithm, Error, OathType};
+use sha1::Digest as _;
+use std::collections::HashMap;
+
+/// Credential parameters parsed from an `otpauth://` URI.
+#[derive(Debug)]
+pub struct OtpAuth {
+    pub name: String,
+    pub oath_type: OathType,
+    pub algorithm: Algorithm,
+    pub digits: u8,
+    pub secret: Vec<u8>,
+    pub counter: Option<u32>,
+}
+
+/// Parses an `otpauth://totp/...` or `otpauth://hotp/...` URI into [`OtpAuth`].
+///
+/// The secret is base32-decoded and, per the YKOATH protocol, HMAC-SHA pre-hashed down to the
+/// digest length if it is longer than the algorithm's block size.
+pub fn parse(uri: &str) -> Result<OtpAuth, Error> {
+    let rest = uri
+        .strip_prefix("otpauth://")
+        .ok_or_else(|| Error::InvalidUri("missing otpauth:// scheme".into()))?;
+    let (oath_type, rest) = if let Some(rest) = rest.strip_prefix("totp/") {
+        (OathType::Totp, rest)
+    } else if let Some(rest) = rest.strip_prefix("hotp/") {
+        (OathType::Hotp, rest)
+    } else {
+        return Err(Error::InvalidUri("unknown otpauth type".into()));
+    };
+
+    let (label, query) = rest
+        .split_once('?')
+        .ok_or_else(|| Error::InvalidUri("missing query parameters".into()))?;
+    let name = percent_encoding::percent_decode_str(label)
+        .decode_utf8()
+        .map_err(|_| Error::InvalidUri("label is not valid utf8".into()))?
+        .into_owned();
+
+    let params: HashMap<&str, String> = query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(key, value)| {
+            (
+                key,
+                percent_encoding::percent_decode_str(value)
+                    .decode_utf8_lossy()
+                    .into_owned(),
+            )
+        })
+        .collect();
+
+    let secret = params
+        .get("secret")
+        .ok_or_else(|| Error::InvalidUri("missing secret parameter".into()))?;
+    let secret = data_encoding::BASE32_NOPAD
+        .decode(secret.trim_end_matches('=').to_ascii_uppercase().as_bytes())
+        .map_err(|_| Error::InvalidUri("invalid base32 secret".into()))?;
+
+    let algorithm = match params.get("algorithm").map(String::as_str) {
+        None | Some("SHA1") => Algorithm::HmacSha1,
+        Some("SHA256") => Algorithm::HmacSha256,
+        Some("SHA512") => Algorithm::HmacSha512,
+        Some(other) => return Err(Error::InvalidUri(format!("unknown algorithm {other}"))),
+    };
+    let secret = normalize_secret(secret, &algorithm);
+
+    let digits = match params.get("digits") {
+        Some(digits) => digits
+            .parse()
+            .map_err(|_| Error::InvalidUri("invalid digits".into()))?,
+        None => 6,
+    };
+
+    let counter = match oath_type {
+        OathType::Hotp => Some(match params.get("counter") {
+            Some(counter) => counter
+                .parse()
+                .map_err(|_| Error::InvalidUri("invalid counter".into()))?,
+            None => 0,
+        }),
+        OathType::Totp => None,
+    };
+
+    Ok(OtpAuth {
+        name,
+        oath_type,
+        algorithm,
+        digits,
+        secret,
+        counter,
+    })
+}
+
+/// HMAC-SHA pre-hashes a secret down to the digest length if it exceeds the algorithm's block
+/// size, per the YKOATH protocol.
+fn normalize_secret(secret: Vec<u8>, algorithm: &Algorithm) -> Vec<u8> {
+    let block_size = match algorithm {
+        Algorithm::HmacSha1 | Algorithm::HmacSha256 => 64,
+        Algorithm::HmacSha512 => 128,
+    };
+    if secret.len() <= block_size {
+        return secret;
+    }
+    match algorithm {
+        Algorithm::HmacSha1 => sha1::Sha1::digest(&secret).to_vec(),
+        Algorithm::HmacSha256 => sha2::Sha256::digest(&secret).to_vec(),
+        Algorithm::HmacSha512 => sha2::Sha512::digest(&secret).to_vec(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_totp_uri() {
+        let otp = parse(
+            "otpauth://totp/Example:alice@example.com?secret=JBSWY3DPEHPK3PXP&issuer=Example",
+        )
+        .unwrap();
+        assert_eq!(otp.name, "Example:alice@example.com");
+        assert_eq!(otp.oath_type, OathType::Totp);
+        assert_eq!(otp.algorithm.code(), Algorithm::HmacSha1.code());
+        assert_eq!(otp.digits, 6);
+        assert_eq!(otp.secret, b"Hello!\xde\xad\xbe\xef");
+        assert_eq!(otp.counter, None);
+    }
+
+    #[test]
+    fn parses_hotp_uri_with_counter() {
+        let otp =
+            parse("otpauth://hotp/Example:bob@example.com?secret=JBSWY3DPEHPK3PXP&counter=5")
+                .unwrap();
+        assert_eq!(otp.oath_type, OathType::Hotp);
+        assert_eq!(otp.counter, Some(5));
+    }
+
+    #[test]
+    fn missing_secret_is_an_error() {
+        let err = parse("otpauth://totp/Example:alice@example.com?issuer=Example").unwrap_err();
+        assert!(matches!(err, Error::InvalidUri(_)));
+    }
+
+    #[test]
+    fn prehashes_oversized_secret() {
+        let raw_secret = vec![0x42; 70];
+        let encoded = data_encoding::BASE32_NOPAD.encode(&raw_secret);
+        let uri = format!("otpauth://totp/Example:alice@example.com?secret={encoded}");
+        let otp = parse(&uri).unwrap();
+        assert_eq!(otp.secret, sha1::Sha1::digest(&raw_secret).to_vec());
+    }
+}
@@ -0,0 +1,43 @@
+use crate::apdu::Apdu;
+use crate::{Algorithm, Error, OathType, YubiKey};
+
+impl YubiKey {
+    /// Adds a new credential to the device. `counter` is ignored for [`OathType::Totp`].
+    #[tracing::instrument(skip(self, secret, buf))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn put(
+        &self,
+        name: &[u8],
+        oath_type: OathType,
+        algorithm: Algorithm,
+        digits: u8,
+        secret: &[u8],
+        counter: Option<u32>,
+        require_touch: bool,
+        increasing: bool,
+        buf: &mut Vec<u8>,
+    ) -> Result<(), Error> {
+        let mut key_data = Vec::with_capacity(2 + secret.len());
+        key_data.push(oath_type.code() | algorithm.code());
+        key_data.push(digits);
+        key_data.extend_from_slice(secret);
+
+        let mut flags = 0u8;
+        if require_touch {
+            flags |= 0x02;
+        }
+        if increasing {
+            flags |= 0x01;
+        }
+
+        let mut apdu = Apdu::new(buf, 0x00, 0x01, 0x00, 0x00)
+            .push(0x71, name)
+            .push(0x73, key_data.as_slice())
+            .push_if(flags != 0, 0x78, &[flags][..]);
+        if let (OathType::Hotp, Some(counter)) = (oath_type, counter) {
+            apdu = apdu.push(0x7a, &counter.to_be_bytes()[..]);
+        }
+        self.transmit(apdu)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,13 @@
+use crate::apdu::Apdu;
+use crate::{Error, YubiKey};
+
+impl YubiKey {
+    /// Wipes the OATH applet, deleting every stored credential and clearing any password set
+    /// via [`set_code`](YubiKey::set_code).
+    #[tracing::instrument(skip(self, buf))]
+    pub fn reset(&self, buf: &mut Vec<u8>) -> Result<(), Error> {
+        let apdu = Apdu::new(buf, 0x00, 0x04, 0xde, 0xad);
+        self.transmit(apdu)?;
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+use crate::apdu::Apdu;
 use crate::{Algorithm, Error, EscapeAscii, YubiKey};
 use std::fmt;
 
@@ -26,11 +27,9 @@ pub struct Inner<'a> {
 impl YubiKey {
     #[tracing::instrument(skip(self, buf))]
     pub fn select<'a>(&self, buf: &'a mut Vec<u8>) -> Result<Response<'a>, Error> {
-        buf.clear();
-        buf.extend_from_slice(&[0x00, 0xa4, 0x04, 0x00]);
-        buf.push(0x00);
-        buf.extend_from_slice(&[0xa0, 0x00, 0x00, 0x05, 0x27, 0x21, 0x01]);
-        let mut response = self.transmit(buf)?;
+        let apdu =
+            Apdu::new(buf, 0x00, 0xa4, 0x04, 0x00).raw(&[0xa0, 0x00, 0x00, 0x05, 0x27, 0x21, 0x01]);
+        let mut response = self.transmit(apdu)?;
         let (_, version) = Self::pop(&mut response, &[0x79])?;
         let (_, name) = Self::pop(&mut response, &[0x71])?;
         let inner = if response.is_empty() {
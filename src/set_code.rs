@@ -0,0 +1,37 @@
+use crate::apdu::Apdu;
+use crate::{Algorithm, Error, OathType, YubiKey};
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+
+type HmacSha1 = Hmac<Sha1>;
+
+impl YubiKey {
+    /// Sets a password on the OATH applet, after which [`validate`](YubiKey::validate) is
+    /// required before `calculate`/`calculate_all` will work.
+    #[tracing::instrument(skip(self, key, buf))]
+    pub fn set_code(&self, key: &[u8], buf: &mut Vec<u8>) -> Result<(), Error> {
+        let challenge: [u8; 8] = rand::random();
+        let mut mac = HmacSha1::new_from_slice(key).expect("HMAC can take key of any size");
+        mac.update(&challenge);
+        let response = mac.finalize().into_bytes();
+
+        let mut key_data = Vec::with_capacity(1 + key.len());
+        key_data.push(OathType::Totp.code() | Algorithm::HmacSha1.code());
+        key_data.extend_from_slice(key);
+
+        let apdu = Apdu::new(buf, 0x00, 0x03, 0x00, 0x00)
+            .push(0x73, key_data.as_slice())
+            .push(0x74, &challenge[..])
+            .push(0x75, response.as_slice());
+        self.transmit(apdu)?;
+        Ok(())
+    }
+
+    /// Clears a password previously set with [`set_code`](YubiKey::set_code).
+    #[tracing::instrument(skip(self, buf))]
+    pub fn clear_code(&self, buf: &mut Vec<u8>) -> Result<(), Error> {
+        let apdu = Apdu::new(buf, 0x00, 0x03, 0x00, 0x00).push(0x73, &[][..]);
+        self.transmit(apdu)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+use crate::apdu::Apdu;
+use crate::{Error, YubiKey};
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+
+type HmacSha1 = Hmac<Sha1>;
+
+impl YubiKey {
+    /// Authenticates against a password-protected OATH applet using `key` (see
+    /// [`crate::derive_key`]) and the `challenge` from [`select`](YubiKey::select).
+    #[tracing::instrument(skip(self, key, challenge, buf))]
+    pub fn validate(&self, key: &[u8], challenge: &[u8], buf: &mut Vec<u8>) -> Result<(), Error> {
+        let our_challenge: [u8; 8] = rand::random();
+
+        let mut mac = HmacSha1::new_from_slice(key).expect("HMAC can take key of any size");
+        mac.update(challenge);
+        let our_response = mac.finalize().into_bytes();
+
+        let apdu = Apdu::new(buf, 0x00, 0xa3, 0x00, 0x00)
+            .push(0x75, our_response.as_slice())
+            .push(0x74, &our_challenge[..]);
+        let mut response = self.transmit(apdu)?;
+        let (_, their_response) = Self::pop(&mut response, &[0x75])?;
+
+        let mut mac = HmacSha1::new_from_slice(key).expect("HMAC can take key of any size");
+        mac.update(&our_challenge);
+        mac.verify_slice(their_response).map_err(|_| Error::AuthFailed)?;
+
+        Ok(())
+    }
+}